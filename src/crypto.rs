@@ -0,0 +1,9 @@
+#[cfg(feature = "std")]
+pub mod aes_gcm;
+#[cfg(feature = "std")]
+pub mod aes_siv;
+pub mod crypto_trait;
+#[cfg(feature = "std")]
+pub mod stream;
+#[cfg(feature = "std")]
+pub mod xchacha20poly1305;
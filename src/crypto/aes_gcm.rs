@@ -0,0 +1,133 @@
+use crate::crypto::crypto_trait::CryptoCipherTrait;
+use crate::error::NimbusError;
+use crate::utils::random::generate_aes_gcm_nonce;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng as AeadOsRng, Payload};
+use aes_gcm::{Aes256Gcm as Aes256GcmImpl, Key, Nonce};
+
+/// AES-256-GCM authenticated encryption, implementing [`CryptoCipherTrait`].
+///
+/// Backed by the RustCrypto `aes-gcm` crate. Callers must never reuse a
+/// nonce with the same key; prefer [`Aes256Gcm::generate_nonce`] for every
+/// encryption.
+pub struct Aes256Gcm;
+
+impl CryptoCipherTrait for Aes256Gcm {
+    type Error = NimbusError;
+    type Key = Key<Aes256GcmImpl>;
+    type Nonce = Nonce<<Aes256GcmImpl as AeadCore>::NonceSize>;
+
+    const KEY_SIZE: usize = 32;
+    const NONCE_SIZE: usize = 12;
+
+    fn get_key_from_u8_array(key: &[u8]) -> Result<Self::Key, Self::Error> {
+        if key.len() != Self::KEY_SIZE {
+            return Err(NimbusError::InvalidLength);
+        }
+        Ok(*Key::<Aes256GcmImpl>::from_slice(key))
+    }
+
+    fn get_nonce_from_u8_array(nonce: &[u8]) -> Result<Self::Nonce, Self::Error> {
+        if nonce.len() != Self::NONCE_SIZE {
+            return Err(NimbusError::InvalidLength);
+        }
+        Ok(*Nonce::from_slice(nonce))
+    }
+
+    fn encrypt(
+        key: &Self::Key,
+        nonce: &Self::Nonce,
+        plaintext: &[u8],
+        additional_associated_data: &[u8],
+    ) -> Result<Vec<u8>, Self::Error> {
+        let cipher = Aes256GcmImpl::new(key);
+        cipher
+            .encrypt(
+                nonce,
+                Payload {
+                    msg: plaintext,
+                    aad: additional_associated_data,
+                },
+            )
+            .map_err(|_| NimbusError::CryptographicFailure)
+    }
+
+    fn decrypt(
+        key: &Self::Key,
+        nonce: &Self::Nonce,
+        ciphertext: &[u8],
+        additional_associated_data: &[u8],
+    ) -> Result<Vec<u8>, Self::Error> {
+        let cipher = Aes256GcmImpl::new(key);
+        cipher
+            .decrypt(
+                nonce,
+                Payload {
+                    msg: ciphertext,
+                    aad: additional_associated_data,
+                },
+            )
+            .map_err(|_| NimbusError::AuthenticationFailed)
+    }
+
+    fn generate_key() -> Result<Self::Key, Self::Error> {
+        Ok(Aes256GcmImpl::generate_key(AeadOsRng))
+    }
+
+    fn generate_nonce() -> Result<Self::Nonce, Self::Error> {
+        let bytes = generate_aes_gcm_nonce()?;
+        Self::get_nonce_from_u8_array(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_plaintext() {
+        let key = Aes256Gcm::generate_key().unwrap();
+        let nonce = Aes256Gcm::generate_nonce().unwrap();
+        let ciphertext = Aes256Gcm::encrypt(&key, &nonce, b"hello aes-gcm", b"aad").unwrap();
+        let plaintext = Aes256Gcm::decrypt(&key, &nonce, &ciphertext, b"aad").unwrap();
+        assert_eq!(plaintext, b"hello aes-gcm");
+    }
+
+    #[test]
+    fn rejects_wrong_associated_data() {
+        let key = Aes256Gcm::generate_key().unwrap();
+        let nonce = Aes256Gcm::generate_nonce().unwrap();
+        let ciphertext = Aes256Gcm::encrypt(&key, &nonce, b"hello", b"aad one").unwrap();
+        let result = Aes256Gcm::decrypt(&key, &nonce, &ciphertext, b"aad two");
+        assert_eq!(result.unwrap_err(), NimbusError::AuthenticationFailed);
+    }
+
+    #[test]
+    fn rejects_tampered_ciphertext() {
+        let key = Aes256Gcm::generate_key().unwrap();
+        let nonce = Aes256Gcm::generate_nonce().unwrap();
+        let mut ciphertext = Aes256Gcm::encrypt(&key, &nonce, b"hello", b"aad").unwrap();
+        let last_index = ciphertext.len() - 1;
+        ciphertext[last_index] ^= 0xFF;
+        let result = Aes256Gcm::decrypt(&key, &nonce, &ciphertext, b"aad");
+        assert_eq!(result.unwrap_err(), NimbusError::AuthenticationFailed);
+    }
+
+    #[test]
+    fn rejects_key_of_wrong_length() {
+        let result = Aes256Gcm::get_key_from_u8_array(&[0u8; 16]);
+        assert_eq!(result.unwrap_err(), NimbusError::InvalidLength);
+    }
+
+    #[test]
+    fn rejects_nonce_of_wrong_length() {
+        let result = Aes256Gcm::get_nonce_from_u8_array(&[0u8; 8]);
+        assert_eq!(result.unwrap_err(), NimbusError::InvalidLength);
+    }
+
+    #[test]
+    fn generated_nonces_are_different() {
+        let first = Aes256Gcm::generate_nonce().unwrap();
+        let second = Aes256Gcm::generate_nonce().unwrap();
+        assert_ne!(first, second);
+    }
+}
@@ -0,0 +1,101 @@
+use crate::crypto::crypto_trait::DeterministicCipherTrait;
+use crate::error::NimbusError;
+use aes_siv::aead::{Aead, KeyInit, OsRng as AeadOsRng, Payload};
+use aes_siv::{Aes256SivAead, Key, Nonce};
+
+/// AES-256-SIV misuse-resistant authenticated encryption, implementing
+/// [`DeterministicCipherTrait`].
+///
+/// Encryption derives its IV synthetically via an S2V (CMAC-based) PRF over
+/// the associated data and plaintext, then uses that IV as the AES-CTR IV.
+/// Identical `(associated_data, plaintext)` pairs under the same key always
+/// produce identical ciphertext. The key is 64 bytes: two independent
+/// 256-bit keys, one for the S2V/CMAC PRF and one for AES-CTR.
+pub struct AesSiv;
+
+impl DeterministicCipherTrait for AesSiv {
+    type Error = NimbusError;
+    type Key = Key<Aes256SivAead>;
+
+    const KEY_SIZE: usize = 64;
+
+    fn get_key_from_u8_array(key: &[u8]) -> Result<Self::Key, Self::Error> {
+        if key.len() != Self::KEY_SIZE {
+            return Err(NimbusError::InvalidLength);
+        }
+        Ok(*Key::<Aes256SivAead>::from_slice(key))
+    }
+
+    fn encrypt(
+        key: &Self::Key,
+        plaintext: &[u8],
+        associated_data: &[u8],
+    ) -> Result<Vec<u8>, Self::Error> {
+        let cipher = Aes256SivAead::new(key);
+        cipher
+            .encrypt(
+                &Nonce::default(),
+                Payload {
+                    msg: plaintext,
+                    aad: associated_data,
+                },
+            )
+            .map_err(|_| NimbusError::CryptographicFailure)
+    }
+
+    fn decrypt(
+        key: &Self::Key,
+        ciphertext: &[u8],
+        associated_data: &[u8],
+    ) -> Result<Vec<u8>, Self::Error> {
+        let cipher = Aes256SivAead::new(key);
+        cipher
+            .decrypt(
+                &Nonce::default(),
+                Payload {
+                    msg: ciphertext,
+                    aad: associated_data,
+                },
+            )
+            .map_err(|_| NimbusError::AuthenticationFailed)
+    }
+
+    fn generate_key() -> Result<Self::Key, Self::Error> {
+        Ok(Aes256SivAead::generate_key(AeadOsRng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_inputs_produce_identical_ciphertext() {
+        let key = AesSiv::generate_key().unwrap();
+        let first = AesSiv::encrypt(&key, b"same message", b"same aad").unwrap();
+        let second = AesSiv::encrypt(&key, b"same message", b"same aad").unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn round_trips_plaintext() {
+        let key = AesSiv::generate_key().unwrap();
+        let ciphertext = AesSiv::encrypt(&key, b"hello misuse resistance", b"aad").unwrap();
+        let plaintext = AesSiv::decrypt(&key, &ciphertext, b"aad").unwrap();
+        assert_eq!(plaintext, b"hello misuse resistance");
+    }
+
+    #[test]
+    fn rejects_wrong_associated_data() {
+        let key = AesSiv::generate_key().unwrap();
+        let ciphertext = AesSiv::encrypt(&key, b"hello", b"aad one").unwrap();
+        let result = AesSiv::decrypt(&key, &ciphertext, b"aad two");
+        assert_eq!(result.unwrap_err(), NimbusError::AuthenticationFailed);
+    }
+
+    #[test]
+    fn rejects_key_of_wrong_length() {
+        let result = AesSiv::get_key_from_u8_array(&[0u8; 32]);
+        assert_eq!(result.unwrap_err(), NimbusError::InvalidLength);
+    }
+}
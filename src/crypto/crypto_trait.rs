@@ -1,3 +1,6 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
 /// A trait for cryptographic cipher implementations providing authenticated encryption.
 ///
 /// This trait defines the interface for symmetric encryption algorithms that provide
@@ -73,3 +76,63 @@ pub trait CryptoCipherTrait {
     /// or other system-level issues.
     fn generate_nonce() -> Result<Self::Nonce, Self::Error>;
 }
+
+/// A trait for misuse-resistant deterministic authenticated encryption.
+///
+/// Unlike [`CryptoCipherTrait`], implementations of this trait derive their
+/// internal IV synthetically from the key, associated data, and plaintext
+/// rather than accepting a caller-supplied nonce that must never repeat.
+/// Encrypting the same associated data and plaintext twice under the same
+/// key always yields the same ciphertext, so repeated inputs only reveal
+/// that they are equal rather than breaking confidentiality outright. A
+/// caller that still wants a per-message nonce can simply fold it into the
+/// associated data passed to [`Self::encrypt`].
+pub trait DeterministicCipherTrait {
+    /// The error type returned by operations in this trait.
+    type Error;
+    /// The key type used for encryption and decryption.
+    type Key;
+
+    /// The size of the key in bytes.
+    const KEY_SIZE: usize;
+
+    /// Creates a key from a byte array.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the provided byte array has an invalid length or format
+    /// for the key type.
+    fn get_key_from_u8_array(key: &[u8]) -> Result<Self::Key, Self::Error>;
+
+    /// Deterministically encrypts plaintext using authenticated encryption.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the encryption operation fails due to invalid parameters
+    /// or internal cryptographic errors.
+    fn encrypt(
+        key: &Self::Key,
+        plaintext: &[u8],
+        associated_data: &[u8],
+    ) -> Result<Vec<u8>, Self::Error>;
+
+    /// Decrypts ciphertext using authenticated decryption.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the decryption operation fails due to authentication
+    /// failure, invalid parameters, or internal cryptographic errors.
+    fn decrypt(
+        key: &Self::Key,
+        ciphertext: &[u8],
+        associated_data: &[u8],
+    ) -> Result<Vec<u8>, Self::Error>;
+
+    /// Generates a new cryptographically secure key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key generation fails due to insufficient entropy
+    /// or other system-level issues.
+    fn generate_key() -> Result<Self::Key, Self::Error>;
+}
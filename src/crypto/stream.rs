@@ -0,0 +1,394 @@
+//! Chunked streaming authenticated encryption using the STREAM construction.
+//!
+//! [`StreamEncryptor`] and [`StreamDecryptor`] wrap any [`CryptoCipherTrait`]
+//! to process a payload as a sequence of segments rather than one in-memory
+//! buffer. Each segment is emitted as a length-prefixed frame: an 8-byte
+//! big-endian length followed by that many bytes of ciphertext (including
+//! the authentication tag).
+//!
+//! Each segment's nonce is `random_prefix || u32_be(counter) || last_flag`,
+//! where the prefix fills all but the final five nonce bytes and `counter`
+//! increments once per segment. Both sides derive the nonce from their own
+//! counter state instead of reading one off the wire, so a reordered,
+//! skipped, or duplicated segment simply authenticates under the wrong
+//! nonce and fails; a stream truncated before its true final segment ends
+//! on a frame encrypted with `last_flag = 0`, which fails authentication if
+//! decrypted as the final segment.
+
+use crate::crypto::crypto_trait::CryptoCipherTrait;
+use crate::error::{NimbusError, NimbusResult};
+use crate::utils::random::SecureRandomSource;
+use rand::rngs::OsRng;
+use std::marker::PhantomData;
+
+/// Number of nonce bytes consumed by the counter (4 bytes) and the
+/// last-segment flag (1 byte) in the STREAM nonce layout.
+const COUNTER_AND_FLAG_SIZE: usize = 5;
+
+/// Default plaintext segment size: 64 KiB.
+pub const DEFAULT_SEGMENT_SIZE: usize = 64 * 1024;
+
+/// Length of the big-endian frame length prefix, in bytes.
+const FRAME_LENGTH_PREFIX_SIZE: usize = 8;
+
+fn random_prefix(len: usize) -> NimbusResult<Vec<u8>> {
+    let mut prefix = vec![0u8; len];
+    let mut rng = OsRng;
+    SecureRandomSource::try_fill_bytes(&mut rng, &mut prefix)
+        .map_err(|_| NimbusError::RandomGenerationFailed)?;
+    Ok(prefix)
+}
+
+fn build_nonce<C: CryptoCipherTrait<Error = NimbusError>>(
+    prefix: &[u8],
+    counter: u32,
+    last: bool,
+) -> NimbusResult<C::Nonce> {
+    let mut bytes = Vec::with_capacity(C::NONCE_SIZE);
+    bytes.extend_from_slice(prefix);
+    bytes.extend_from_slice(&counter.to_be_bytes());
+    bytes.push(u8::from(last));
+    C::get_nonce_from_u8_array(&bytes)
+}
+
+fn write_frame(out: &mut Vec<u8>, segment: &[u8]) {
+    out.extend_from_slice(&(segment.len() as u64).to_be_bytes());
+    out.extend_from_slice(segment);
+}
+
+/// Splits the next length-prefixed frame off the front of `data`, returning
+/// the frame's payload and the remaining, unconsumed bytes.
+///
+/// # Errors
+///
+/// Returns [`NimbusError::InvalidInput`] if `data` is too short to contain a
+/// complete length prefix and payload.
+fn read_frame(data: &[u8]) -> NimbusResult<(&[u8], &[u8])> {
+    if data.len() < FRAME_LENGTH_PREFIX_SIZE {
+        return Err(NimbusError::InvalidInput);
+    }
+    let (len_bytes, rest) = data.split_at(FRAME_LENGTH_PREFIX_SIZE);
+    let len = u64::from_be_bytes(len_bytes.try_into().expect("exactly 8 bytes")) as usize;
+    if rest.len() < len {
+        return Err(NimbusError::InvalidInput);
+    }
+    Ok(rest.split_at(len))
+}
+
+/// Encrypts a plaintext stream as a sequence of length-prefixed,
+/// STREAM-nonce-derived segments.
+pub struct StreamEncryptor<C: CryptoCipherTrait<Error = NimbusError>> {
+    key: C::Key,
+    nonce_prefix: Vec<u8>,
+    counter: u32,
+    finished: bool,
+    _cipher: PhantomData<C>,
+}
+
+impl<C: CryptoCipherTrait<Error = NimbusError>> StreamEncryptor<C> {
+    /// Creates a new encryptor with a freshly generated random nonce prefix.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NimbusError::RandomGenerationFailed`] if the nonce prefix
+    /// cannot be generated.
+    pub fn new(key: C::Key) -> NimbusResult<Self> {
+        let nonce_prefix = random_prefix(C::NONCE_SIZE - COUNTER_AND_FLAG_SIZE)?;
+        Ok(Self {
+            key,
+            nonce_prefix,
+            counter: 0,
+            finished: false,
+            _cipher: PhantomData,
+        })
+    }
+
+    /// The random nonce prefix for this stream. Transmit this alongside the
+    /// framed ciphertext; the receiver needs it to construct a matching
+    /// [`StreamDecryptor`].
+    #[must_use]
+    pub fn nonce_prefix(&self) -> &[u8] {
+        &self.nonce_prefix
+    }
+
+    fn next_nonce(&mut self, last: bool) -> NimbusResult<C::Nonce> {
+        let nonce = build_nonce::<C>(&self.nonce_prefix, self.counter, last)?;
+        self.counter = self
+            .counter
+            .checked_add(1)
+            .ok_or(NimbusError::CryptographicFailure)?;
+        Ok(nonce)
+    }
+
+    /// Encrypts a non-final segment and returns its length-prefixed frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NimbusError::InvalidInput`] if the stream has already been
+    /// finalized with [`Self::encrypt_last`], or a cipher error if
+    /// encryption or nonce-counter generation fails.
+    pub fn encrypt_next(&mut self, segment: &[u8], aad: &[u8]) -> NimbusResult<Vec<u8>> {
+        if self.finished {
+            return Err(NimbusError::InvalidInput);
+        }
+        let nonce = self.next_nonce(false)?;
+        let ciphertext = C::encrypt(&self.key, &nonce, segment, aad)?;
+        let mut frame = Vec::with_capacity(FRAME_LENGTH_PREFIX_SIZE + ciphertext.len());
+        write_frame(&mut frame, &ciphertext);
+        Ok(frame)
+    }
+
+    /// Encrypts the final segment of the stream and returns its
+    /// length-prefixed frame. After this call, the encryptor is finished and
+    /// any further call to [`Self::encrypt_next`] or [`Self::encrypt_last`]
+    /// fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NimbusError::InvalidInput`] if the stream was already
+    /// finalized, or a cipher error if encryption or nonce-counter
+    /// generation fails.
+    pub fn encrypt_last(&mut self, segment: &[u8], aad: &[u8]) -> NimbusResult<Vec<u8>> {
+        if self.finished {
+            return Err(NimbusError::InvalidInput);
+        }
+        let nonce = self.next_nonce(true)?;
+        let ciphertext = C::encrypt(&self.key, &nonce, segment, aad)?;
+        self.finished = true;
+        let mut frame = Vec::with_capacity(FRAME_LENGTH_PREFIX_SIZE + ciphertext.len());
+        write_frame(&mut frame, &ciphertext);
+        Ok(frame)
+    }
+
+    /// Encrypts an entire in-memory plaintext as a complete framed stream,
+    /// splitting it into segments of [`DEFAULT_SEGMENT_SIZE`] bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any segment fails to encrypt or the nonce counter
+    /// overflows.
+    pub fn encrypt_all(mut self, plaintext: &[u8], aad: &[u8]) -> NimbusResult<Vec<u8>> {
+        let mut chunks = plaintext.chunks(DEFAULT_SEGMENT_SIZE).peekable();
+        let mut out = Vec::new();
+        loop {
+            let chunk = chunks.next().unwrap_or(&[]);
+            if chunks.peek().is_none() {
+                out.extend_from_slice(&self.encrypt_last(chunk, aad)?);
+                break;
+            }
+            out.extend_from_slice(&self.encrypt_next(chunk, aad)?);
+        }
+        Ok(out)
+    }
+}
+
+/// Decrypts a framed stream produced by [`StreamEncryptor`].
+pub struct StreamDecryptor<C: CryptoCipherTrait<Error = NimbusError>> {
+    key: C::Key,
+    nonce_prefix: Vec<u8>,
+    counter: u32,
+    finished: bool,
+    _cipher: PhantomData<C>,
+}
+
+impl<C: CryptoCipherTrait<Error = NimbusError>> StreamDecryptor<C> {
+    /// Creates a decryptor for the stream identified by `nonce_prefix`, as
+    /// produced by [`StreamEncryptor::nonce_prefix`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NimbusError::InvalidLength`] if `nonce_prefix` is not
+    /// exactly `C::NONCE_SIZE - 5` bytes long.
+    pub fn new(key: C::Key, nonce_prefix: &[u8]) -> NimbusResult<Self> {
+        if nonce_prefix.len() != C::NONCE_SIZE - COUNTER_AND_FLAG_SIZE {
+            return Err(NimbusError::InvalidLength);
+        }
+        Ok(Self {
+            key,
+            nonce_prefix: nonce_prefix.to_vec(),
+            counter: 0,
+            finished: false,
+            _cipher: PhantomData,
+        })
+    }
+
+    /// Whether the final segment has already been decrypted.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    fn next_nonce(&mut self, last: bool) -> NimbusResult<C::Nonce> {
+        let nonce = build_nonce::<C>(&self.nonce_prefix, self.counter, last)?;
+        self.counter = self
+            .counter
+            .checked_add(1)
+            .ok_or(NimbusError::CryptographicFailure)?;
+        Ok(nonce)
+    }
+
+    /// Decrypts a non-final frame's ciphertext.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NimbusError::InvalidInput`] if the stream was already
+    /// finished, or [`NimbusError::AuthenticationFailed`] if the segment was
+    /// reordered, skipped, tampered with, or was actually the stream's final
+    /// segment.
+    pub fn decrypt_next(&mut self, frame_payload: &[u8], aad: &[u8]) -> NimbusResult<Vec<u8>> {
+        if self.finished {
+            return Err(NimbusError::InvalidInput);
+        }
+        let nonce = self.next_nonce(false)?;
+        C::decrypt(&self.key, &nonce, frame_payload, aad)
+    }
+
+    /// Decrypts the stream's final frame. After this call, any further call
+    /// to [`Self::decrypt_next`] or [`Self::decrypt_last`] fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NimbusError::InvalidInput`] if the stream was already
+    /// finished, or [`NimbusError::AuthenticationFailed`] if the segment was
+    /// reordered, tampered with, or was not actually the stream's final
+    /// segment.
+    pub fn decrypt_last(&mut self, frame_payload: &[u8], aad: &[u8]) -> NimbusResult<Vec<u8>> {
+        if self.finished {
+            return Err(NimbusError::InvalidInput);
+        }
+        let nonce = self.next_nonce(true)?;
+        let plaintext = C::decrypt(&self.key, &nonce, frame_payload, aad)?;
+        self.finished = true;
+        Ok(plaintext)
+    }
+
+    /// Decrypts a complete, in-memory framed stream as produced by
+    /// [`StreamEncryptor::encrypt_all`].
+    ///
+    /// Rejects a stream that is truncated before its true final segment: the
+    /// last frame found in `framed` is always decrypted with the "last
+    /// segment" nonce flag, so if the sender's actual final segment was
+    /// dropped, authentication of the remaining last frame fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NimbusError::InvalidInput`] if `framed` is malformed (an
+    /// incomplete frame), or [`NimbusError::AuthenticationFailed`] if any
+    /// segment fails to authenticate.
+    pub fn decrypt_all(mut self, framed: &[u8], aad: &[u8]) -> NimbusResult<Vec<u8>> {
+        let mut plaintext = Vec::new();
+        let mut remaining = framed;
+        loop {
+            let (payload, rest) = read_frame(remaining)?;
+            if rest.is_empty() {
+                plaintext.extend_from_slice(&self.decrypt_last(payload, aad)?);
+                break;
+            }
+            plaintext.extend_from_slice(&self.decrypt_next(payload, aad)?);
+            remaining = rest;
+        }
+        Ok(plaintext)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::aes_gcm::Aes256Gcm;
+
+    fn test_key() -> <Aes256Gcm as CryptoCipherTrait>::Key {
+        Aes256Gcm::get_key_from_u8_array(&[7u8; 32]).unwrap()
+    }
+
+    #[test]
+    fn round_trips_a_multi_segment_stream() {
+        let key = test_key();
+        let encryptor = StreamEncryptor::<Aes256Gcm>::new(key).unwrap();
+        let nonce_prefix = encryptor.nonce_prefix().to_vec();
+        let plaintext = vec![0x42u8; DEFAULT_SEGMENT_SIZE * 3 + 17];
+
+        let framed = encryptor.encrypt_all(&plaintext, b"aad").unwrap();
+
+        let decryptor = StreamDecryptor::<Aes256Gcm>::new(key, &nonce_prefix).unwrap();
+        let decrypted = decryptor.decrypt_all(&framed, b"aad").unwrap();
+        assert_eq!(decrypted, plaintext);
+    }
+
+    #[test]
+    fn round_trips_an_empty_stream() {
+        let key = test_key();
+        let encryptor = StreamEncryptor::<Aes256Gcm>::new(key).unwrap();
+        let nonce_prefix = encryptor.nonce_prefix().to_vec();
+
+        let framed = encryptor.encrypt_all(&[], b"").unwrap();
+
+        let decryptor = StreamDecryptor::<Aes256Gcm>::new(key, &nonce_prefix).unwrap();
+        let decrypted = decryptor.decrypt_all(&framed, b"").unwrap();
+        assert!(decrypted.is_empty());
+    }
+
+    #[test]
+    fn rejects_truncated_stream_missing_final_segment() {
+        let key = test_key();
+        let mut encryptor = StreamEncryptor::<Aes256Gcm>::new(key).unwrap();
+        let nonce_prefix = encryptor.nonce_prefix().to_vec();
+
+        let first = encryptor.encrypt_next(b"segment one", b"aad").unwrap();
+        let _second = encryptor.encrypt_last(b"segment two", b"aad").unwrap();
+
+        // Drop the true final segment: `first` alone is not a valid stream.
+        let decryptor = StreamDecryptor::<Aes256Gcm>::new(key, &nonce_prefix).unwrap();
+        let result = decryptor.decrypt_all(&first, b"aad");
+        assert_eq!(result.unwrap_err(), NimbusError::AuthenticationFailed);
+    }
+
+    #[test]
+    fn rejects_reordered_segments() {
+        let key = test_key();
+        let mut encryptor = StreamEncryptor::<Aes256Gcm>::new(key).unwrap();
+        let nonce_prefix = encryptor.nonce_prefix().to_vec();
+
+        let first = encryptor.encrypt_next(b"segment one", b"aad").unwrap();
+        let second = encryptor.encrypt_last(b"segment two", b"aad").unwrap();
+
+        let mut swapped = Vec::new();
+        let (_, rest) = read_frame(&first).unwrap();
+        assert!(rest.is_empty());
+        swapped.extend_from_slice(&second);
+        swapped.extend_from_slice(&first);
+
+        let decryptor = StreamDecryptor::<Aes256Gcm>::new(key, &nonce_prefix).unwrap();
+        let result = decryptor.decrypt_all(&swapped, b"aad");
+        assert_eq!(result.unwrap_err(), NimbusError::AuthenticationFailed);
+    }
+
+    #[test]
+    fn rejects_tampered_ciphertext() {
+        let key = test_key();
+        let encryptor = StreamEncryptor::<Aes256Gcm>::new(key).unwrap();
+        let nonce_prefix = encryptor.nonce_prefix().to_vec();
+        let mut framed = encryptor.encrypt_all(b"hello stream", b"aad").unwrap();
+        let last_index = framed.len() - 1;
+        framed[last_index] ^= 0xFF;
+
+        let decryptor = StreamDecryptor::<Aes256Gcm>::new(key, &nonce_prefix).unwrap();
+        let result = decryptor.decrypt_all(&framed, b"aad");
+        assert_eq!(result.unwrap_err(), NimbusError::AuthenticationFailed);
+    }
+
+    #[test]
+    fn encrypt_next_after_finished_is_rejected() {
+        let key = test_key();
+        let mut encryptor = StreamEncryptor::<Aes256Gcm>::new(key).unwrap();
+        encryptor.encrypt_last(b"final", b"").unwrap();
+        let result = encryptor.encrypt_next(b"too late", b"");
+        assert_eq!(result.unwrap_err(), NimbusError::InvalidInput);
+    }
+
+    #[test]
+    fn rejects_nonce_prefix_of_wrong_length() {
+        let key = test_key();
+        let result = StreamDecryptor::<Aes256Gcm>::new(key, &[0u8; 3]);
+        assert!(matches!(result, Err(NimbusError::InvalidLength)));
+    }
+}
@@ -0,0 +1,136 @@
+use crate::crypto::crypto_trait::CryptoCipherTrait;
+use crate::error::NimbusError;
+use crate::utils::random::generate_extended_nonce;
+use chacha20poly1305::aead::{Aead, KeyInit, OsRng as AeadOsRng, Payload};
+use chacha20poly1305::{
+    Key, XChaCha20Poly1305 as XChaCha20Poly1305Impl, XNonce,
+};
+
+/// XChaCha20-Poly1305 authenticated encryption, implementing [`CryptoCipherTrait`].
+///
+/// Backed by the RustCrypto `chacha20poly1305` crate. Its extended 192-bit
+/// nonce makes random nonce generation safe even across very large numbers
+/// of messages.
+pub struct XChaCha20Poly1305;
+
+impl CryptoCipherTrait for XChaCha20Poly1305 {
+    type Error = NimbusError;
+    type Key = Key;
+    type Nonce = XNonce;
+
+    const KEY_SIZE: usize = 32;
+    const NONCE_SIZE: usize = 24;
+
+    fn get_key_from_u8_array(key: &[u8]) -> Result<Self::Key, Self::Error> {
+        if key.len() != Self::KEY_SIZE {
+            return Err(NimbusError::InvalidLength);
+        }
+        Ok(*Key::from_slice(key))
+    }
+
+    fn get_nonce_from_u8_array(nonce: &[u8]) -> Result<Self::Nonce, Self::Error> {
+        if nonce.len() != Self::NONCE_SIZE {
+            return Err(NimbusError::InvalidLength);
+        }
+        Ok(*XNonce::from_slice(nonce))
+    }
+
+    fn encrypt(
+        key: &Self::Key,
+        nonce: &Self::Nonce,
+        plaintext: &[u8],
+        additional_associated_data: &[u8],
+    ) -> Result<Vec<u8>, Self::Error> {
+        let cipher = XChaCha20Poly1305Impl::new(key);
+        cipher
+            .encrypt(
+                nonce,
+                Payload {
+                    msg: plaintext,
+                    aad: additional_associated_data,
+                },
+            )
+            .map_err(|_| NimbusError::CryptographicFailure)
+    }
+
+    fn decrypt(
+        key: &Self::Key,
+        nonce: &Self::Nonce,
+        ciphertext: &[u8],
+        additional_associated_data: &[u8],
+    ) -> Result<Vec<u8>, Self::Error> {
+        let cipher = XChaCha20Poly1305Impl::new(key);
+        cipher
+            .decrypt(
+                nonce,
+                Payload {
+                    msg: ciphertext,
+                    aad: additional_associated_data,
+                },
+            )
+            .map_err(|_| NimbusError::AuthenticationFailed)
+    }
+
+    fn generate_key() -> Result<Self::Key, Self::Error> {
+        Ok(XChaCha20Poly1305Impl::generate_key(AeadOsRng))
+    }
+
+    fn generate_nonce() -> Result<Self::Nonce, Self::Error> {
+        let bytes = generate_extended_nonce()?;
+        Self::get_nonce_from_u8_array(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_plaintext() {
+        let key = XChaCha20Poly1305::generate_key().unwrap();
+        let nonce = XChaCha20Poly1305::generate_nonce().unwrap();
+        let ciphertext =
+            XChaCha20Poly1305::encrypt(&key, &nonce, b"hello xchacha20poly1305", b"aad").unwrap();
+        let plaintext = XChaCha20Poly1305::decrypt(&key, &nonce, &ciphertext, b"aad").unwrap();
+        assert_eq!(plaintext, b"hello xchacha20poly1305");
+    }
+
+    #[test]
+    fn rejects_wrong_associated_data() {
+        let key = XChaCha20Poly1305::generate_key().unwrap();
+        let nonce = XChaCha20Poly1305::generate_nonce().unwrap();
+        let ciphertext = XChaCha20Poly1305::encrypt(&key, &nonce, b"hello", b"aad one").unwrap();
+        let result = XChaCha20Poly1305::decrypt(&key, &nonce, &ciphertext, b"aad two");
+        assert_eq!(result.unwrap_err(), NimbusError::AuthenticationFailed);
+    }
+
+    #[test]
+    fn rejects_tampered_ciphertext() {
+        let key = XChaCha20Poly1305::generate_key().unwrap();
+        let nonce = XChaCha20Poly1305::generate_nonce().unwrap();
+        let mut ciphertext = XChaCha20Poly1305::encrypt(&key, &nonce, b"hello", b"aad").unwrap();
+        let last_index = ciphertext.len() - 1;
+        ciphertext[last_index] ^= 0xFF;
+        let result = XChaCha20Poly1305::decrypt(&key, &nonce, &ciphertext, b"aad");
+        assert_eq!(result.unwrap_err(), NimbusError::AuthenticationFailed);
+    }
+
+    #[test]
+    fn rejects_key_of_wrong_length() {
+        let result = XChaCha20Poly1305::get_key_from_u8_array(&[0u8; 16]);
+        assert_eq!(result.unwrap_err(), NimbusError::InvalidLength);
+    }
+
+    #[test]
+    fn rejects_nonce_of_wrong_length() {
+        let result = XChaCha20Poly1305::get_nonce_from_u8_array(&[0u8; 8]);
+        assert_eq!(result.unwrap_err(), NimbusError::InvalidLength);
+    }
+
+    #[test]
+    fn generated_nonces_are_different() {
+        let first = XChaCha20Poly1305::generate_nonce().unwrap();
+        let second = XChaCha20Poly1305::generate_nonce().unwrap();
+        assert_ne!(first, second);
+    }
+}
@@ -1,6 +1,6 @@
 //! Error types for the Nimbus E2EE toolkit.
 
-use std::fmt;
+use core::fmt;
 
 pub type NimbusResult<T> = Result<T, NimbusError>;
 
@@ -14,6 +14,8 @@ pub enum NimbusError {
     RandomGenerationFailed,
     SystemError,
     WebAssemblyError,
+    /// A Base64 decode failed; see [`DecodeError`] for the specific reason.
+    Decode(DecodeError),
 }
 
 impl fmt::Display for NimbusError {
@@ -27,12 +29,54 @@ impl fmt::Display for NimbusError {
             NimbusError::RandomGenerationFailed => write!(f, "Secure random generation failed"),
             NimbusError::SystemError => write!(f, "System operation failed"),
             NimbusError::WebAssemblyError => write!(f, "WebAssembly operation failed"),
+            NimbusError::Decode(reason) => write!(f, "Base64 decode failed: {reason}"),
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for NimbusError {}
 
+#[cfg(not(feature = "std"))]
+impl core::error::Error for NimbusError {}
+
+/// The specific reason a Base64 decode operation failed, distinguishing the
+/// cases the underlying codec can report instead of collapsing them all into
+/// [`NimbusError::InvalidInput`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DecodeError {
+    /// The byte at the given offset is not part of the decoder's alphabet.
+    InvalidByte(usize, u8),
+    /// The input length is not a valid Base64 length.
+    InvalidLength,
+    /// The trailing 6-bit group at the given offset has nonzero discarded
+    /// bits, i.e. a non-canonical final symbol.
+    InvalidLastSymbol(usize, u8),
+    /// The input's padding does not match the decoder's padding policy.
+    InvalidPadding,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::InvalidByte(offset, byte) => {
+                write!(f, "invalid byte 0x{byte:02x} at offset {offset}")
+            }
+            DecodeError::InvalidLength => write!(f, "invalid input length"),
+            DecodeError::InvalidLastSymbol(offset, byte) => {
+                write!(f, "invalid last symbol 0x{byte:02x} at offset {offset}")
+            }
+            DecodeError::InvalidPadding => write!(f, "invalid padding"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+#[cfg(not(feature = "std"))]
+impl core::error::Error for DecodeError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -100,4 +144,36 @@ mod tests {
             "WebAssembly operation failed"
         );
     }
+
+    #[test]
+    fn test_decode_invalid_byte_display() {
+        assert_eq!(
+            NimbusError::Decode(DecodeError::InvalidByte(3, b'?')).to_string(),
+            "Base64 decode failed: invalid byte 0x3f at offset 3"
+        );
+    }
+
+    #[test]
+    fn test_decode_invalid_length_display() {
+        assert_eq!(
+            NimbusError::Decode(DecodeError::InvalidLength).to_string(),
+            "Base64 decode failed: invalid input length"
+        );
+    }
+
+    #[test]
+    fn test_decode_invalid_last_symbol_display() {
+        assert_eq!(
+            NimbusError::Decode(DecodeError::InvalidLastSymbol(7, b'Q')).to_string(),
+            "Base64 decode failed: invalid last symbol 0x51 at offset 7"
+        );
+    }
+
+    #[test]
+    fn test_decode_invalid_padding_display() {
+        assert_eq!(
+            NimbusError::Decode(DecodeError::InvalidPadding).to_string(),
+            "Base64 decode failed: invalid padding"
+        );
+    }
 }
@@ -0,0 +1,195 @@
+//! Password-based key derivation using Argon2id.
+//!
+//! Turns a user-supplied password into key material suitable for
+//! [`crate::crypto::crypto_trait::CryptoCipherTrait`] implementations,
+//! rather than requiring callers to supply raw key bytes.
+
+use crate::crypto::crypto_trait::CryptoCipherTrait;
+use crate::error::{NimbusError, NimbusResult};
+use crate::utils::random::SecureRandomSource;
+use argon2::{Algorithm, Argon2, Params, Version};
+use rand::rngs::OsRng;
+
+/// Default salt size in bytes, generated by [`generate_salt`].
+pub const DEFAULT_SALT_SIZE: usize = 16;
+/// Smallest salt size accepted by [`derive_key`].
+pub const MIN_SALT_SIZE: usize = 8;
+/// Largest salt size accepted by [`derive_key`].
+pub const MAX_SALT_SIZE: usize = 64;
+
+/// Tunable Argon2id cost parameters.
+///
+/// The defaults follow the OWASP-recommended Argon2id baseline: 19 MiB of
+/// memory, 2 iterations, and a single degree of parallelism.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KdfParams {
+    /// Memory cost in KiB.
+    pub memory_cost_kib: u32,
+    /// Number of iterations (time cost).
+    pub iterations: u32,
+    /// Degree of parallelism.
+    pub parallelism: u32,
+    /// Length of the derived key, in bytes.
+    pub output_len: usize,
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        Self {
+            memory_cost_kib: 19 * 1024,
+            iterations: 2,
+            parallelism: 1,
+            output_len: 32,
+        }
+    }
+}
+
+/// Generates a random salt of [`DEFAULT_SALT_SIZE`] bytes.
+///
+/// # Errors
+///
+/// Returns [`NimbusError::RandomGenerationFailed`] if the system's secure
+/// random number generator fails to produce cryptographically secure random
+/// data.
+pub fn generate_salt() -> NimbusResult<Vec<u8>> {
+    generate_salt_of_size(DEFAULT_SALT_SIZE)
+}
+
+/// Generates a random salt of the given size.
+///
+/// # Errors
+///
+/// Returns [`NimbusError::InvalidLength`] if `size` falls outside
+/// [`MIN_SALT_SIZE`]..=[`MAX_SALT_SIZE`], or
+/// [`NimbusError::RandomGenerationFailed`] if secure random generation
+/// fails.
+pub fn generate_salt_of_size(size: usize) -> NimbusResult<Vec<u8>> {
+    if !(MIN_SALT_SIZE..=MAX_SALT_SIZE).contains(&size) {
+        return Err(NimbusError::InvalidLength);
+    }
+    let mut salt = vec![0u8; size];
+    let mut rng = OsRng;
+    SecureRandomSource::try_fill_bytes(&mut rng, &mut salt)
+        .map_err(|_| NimbusError::RandomGenerationFailed)?;
+    Ok(salt)
+}
+
+/// Derives key material from `password` and `salt` using Argon2id.
+///
+/// # Errors
+///
+/// Returns [`NimbusError::InvalidLength`] if `salt` falls outside
+/// [`MIN_SALT_SIZE`]..=[`MAX_SALT_SIZE`], or
+/// [`NimbusError::KeyOperationFailed`] if the underlying Argon2id
+/// computation fails, for example due to invalid cost parameters.
+pub fn derive_key(password: &[u8], salt: &[u8], params: &KdfParams) -> NimbusResult<Vec<u8>> {
+    if !(MIN_SALT_SIZE..=MAX_SALT_SIZE).contains(&salt.len()) {
+        return Err(NimbusError::InvalidLength);
+    }
+    let argon2_params = Params::new(
+        params.memory_cost_kib,
+        params.iterations,
+        params.parallelism,
+        Some(params.output_len),
+    )
+    .map_err(|_| NimbusError::KeyOperationFailed)?;
+    let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, argon2_params);
+    let mut output = vec![0u8; params.output_len];
+    argon2
+        .hash_password_into(password, salt, &mut output)
+        .map_err(|_| NimbusError::KeyOperationFailed)?;
+    Ok(output)
+}
+
+/// Derives a password-based key and wraps it via `C::get_key_from_u8_array`,
+/// overriding `params.output_len` with `C::KEY_SIZE` so the derived output
+/// always matches the cipher's expected key length.
+///
+/// # Errors
+///
+/// Returns [`NimbusError::InvalidLength`] if `salt` is out of range,
+/// [`NimbusError::KeyOperationFailed`] if Argon2id fails, or whatever error
+/// `C::get_key_from_u8_array` returns for malformed key bytes.
+pub fn derive_key_for_cipher<C: CryptoCipherTrait<Error = NimbusError>>(
+    password: &[u8],
+    salt: &[u8],
+    params: &KdfParams,
+) -> NimbusResult<C::Key> {
+    let sized_params = KdfParams {
+        output_len: C::KEY_SIZE,
+        ..*params
+    };
+    let key_bytes = derive_key(password, salt, &sized_params)?;
+    C::get_key_from_u8_array(&key_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::aes_gcm::Aes256Gcm;
+
+    #[test]
+    fn derive_key_is_deterministic_for_same_inputs() {
+        let params = KdfParams::default();
+        let salt = b"0123456789abcdef";
+        let first = derive_key(b"correct horse", salt, &params).unwrap();
+        let second = derive_key(b"correct horse", salt, &params).unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn derive_key_differs_for_different_passwords() {
+        let params = KdfParams::default();
+        let salt = b"0123456789abcdef";
+        let first = derive_key(b"correct horse", salt, &params).unwrap();
+        let second = derive_key(b"incorrect horse", salt, &params).unwrap();
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn derive_key_respects_output_len() {
+        let params = KdfParams {
+            output_len: 16,
+            ..KdfParams::default()
+        };
+        let salt = b"0123456789abcdef";
+        let key = derive_key(b"password", salt, &params).unwrap();
+        assert_eq!(key.len(), 16);
+    }
+
+    #[test]
+    fn derive_key_rejects_salt_too_short() {
+        let params = KdfParams::default();
+        let result = derive_key(b"password", b"short", &params);
+        assert_eq!(result.unwrap_err(), NimbusError::InvalidLength);
+    }
+
+    #[test]
+    fn derive_key_for_cipher_produces_usable_cipher_key() {
+        let params = KdfParams::default();
+        let salt = generate_salt().unwrap();
+        let key = derive_key_for_cipher::<Aes256Gcm>(b"password", &salt, &params).unwrap();
+        let nonce = Aes256Gcm::generate_nonce().unwrap();
+        let ciphertext = Aes256Gcm::encrypt(&key, &nonce, b"hello", b"").unwrap();
+        let plaintext = Aes256Gcm::decrypt(&key, &nonce, &ciphertext, b"").unwrap();
+        assert_eq!(plaintext, b"hello");
+    }
+
+    #[test]
+    fn generate_salt_of_size_rejects_out_of_range_size() {
+        assert_eq!(
+            generate_salt_of_size(4).unwrap_err(),
+            NimbusError::InvalidLength
+        );
+        assert_eq!(
+            generate_salt_of_size(128).unwrap_err(),
+            NimbusError::InvalidLength
+        );
+    }
+
+    #[test]
+    fn generate_salt_has_default_size() {
+        let salt = generate_salt().unwrap();
+        assert_eq!(salt.len(), DEFAULT_SALT_SIZE);
+    }
+}
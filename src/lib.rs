@@ -0,0 +1,23 @@
+//! Nimbus: a small toolkit of authenticated-encryption, encoding, and
+//! randomness primitives for building end-to-end encrypted applications.
+//!
+//! The crate is `no_std` + `alloc` compatible: build with
+//! `--no-default-features --features alloc` to use [`error::NimbusError`],
+//! [`utils::random::SecureRandomSource`], [`utils::encoding::Encoder`], and
+//! [`crypto::crypto_trait::CryptoCipherTrait`] on embedded or WASM targets
+//! without an operating system. `OsRng`-backed random helpers and the
+//! concrete cipher/KDF implementations require the default `std` feature,
+//! since they depend on the OS entropy source.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+pub mod crypto;
+pub mod error;
+#[cfg(feature = "std")]
+pub mod kdf;
+#[cfg(feature = "std")]
+pub mod sign;
+pub mod utils;
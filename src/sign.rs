@@ -0,0 +1,218 @@
+//! Detached ECDSA/secp256k1 digital signatures for sender authentication.
+//!
+//! [`SecretKey::from_bytes`] and [`PublicKey::from_bytes`] validate their
+//! input (scalar range, curve membership) at construction time, so every
+//! live [`SecretKey`]/[`PublicKey`] value is already known-good. That lets
+//! [`sign`] take a validated key and a fixed-size digest and return a
+//! [`Signature`] directly, with no `Result` to unwrap.
+
+use crate::error::{NimbusError, NimbusResult};
+use crate::utils::encoding::{decode_base64, encode_base64};
+use crate::utils::random::SecureRandomSource;
+use rand::rngs::OsRng;
+use secp256k1::ecdsa::Signature as RawSignature;
+use secp256k1::{Message, PublicKey as RawPublicKey, Secp256k1, SecretKey as RawSecretKey};
+
+/// Length of a secp256k1 secret key scalar, in bytes.
+pub const SECRET_KEY_SIZE: usize = 32;
+/// Length of a compact `r || s` signature, in bytes.
+pub const SIGNATURE_SIZE: usize = 64;
+
+/// A validated secp256k1 secret (signing) key.
+#[derive(Clone, Copy)]
+pub struct SecretKey(RawSecretKey);
+
+impl SecretKey {
+    /// Validates and wraps a 32-byte scalar as a secret key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NimbusError::InvalidInput`] if `bytes` is not a valid
+    /// secp256k1 scalar (wrong length, zero, or out of curve order).
+    pub fn from_bytes(bytes: &[u8]) -> NimbusResult<Self> {
+        RawSecretKey::from_slice(bytes)
+            .map(Self)
+            .map_err(|_| NimbusError::InvalidInput)
+    }
+
+    /// Generates a new random secret key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NimbusError::RandomGenerationFailed`] if the system's
+    /// secure random number generator fails.
+    pub fn generate() -> NimbusResult<Self> {
+        let mut bytes = [0u8; SECRET_KEY_SIZE];
+        let mut rng = OsRng;
+        SecureRandomSource::try_fill_bytes(&mut rng, &mut bytes)
+            .map_err(|_| NimbusError::RandomGenerationFailed)?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Derives the corresponding public key.
+    #[must_use]
+    pub fn public_key(&self) -> PublicKey {
+        let secp = Secp256k1::new();
+        PublicKey(RawPublicKey::from_secret_key(&secp, &self.0))
+    }
+}
+
+/// A validated secp256k1 public (verifying) key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublicKey(RawPublicKey);
+
+impl PublicKey {
+    /// Validates and wraps compressed or uncompressed SEC1 bytes as a public
+    /// key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NimbusError::InvalidInput`] if `bytes` does not encode a
+    /// valid point on the secp256k1 curve.
+    pub fn from_bytes(bytes: &[u8]) -> NimbusResult<Self> {
+        RawPublicKey::from_slice(bytes)
+            .map(Self)
+            .map_err(|_| NimbusError::InvalidInput)
+    }
+}
+
+/// A compact, fixed-size secp256k1 ECDSA signature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Signature([u8; SIGNATURE_SIZE]);
+
+impl Signature {
+    /// The raw 64-byte compact `r || s` signature.
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; SIGNATURE_SIZE] {
+        self.0
+    }
+
+    /// Wraps raw compact signature bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NimbusError::InvalidLength`] if `bytes` is not exactly
+    /// [`SIGNATURE_SIZE`] bytes.
+    pub fn from_bytes(bytes: &[u8]) -> NimbusResult<Self> {
+        let array: [u8; SIGNATURE_SIZE] =
+            bytes.try_into().map_err(|_| NimbusError::InvalidLength)?;
+        Ok(Self(array))
+    }
+
+    /// Base64-encodes the signature for transport.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if the underlying Base64 encoder rejects the
+    /// input, which cannot happen for a fixed 64-byte signature.
+    pub fn to_base64(&self) -> NimbusResult<String> {
+        encode_base64(&self.0)
+    }
+
+    /// Decodes a compact signature from its Base64 transport encoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NimbusError::Decode` if `encoded` is malformed Base64, or
+    /// [`NimbusError::InvalidLength`] if it does not decode to exactly
+    /// [`SIGNATURE_SIZE`] bytes.
+    pub fn from_base64(encoded: &str) -> NimbusResult<Self> {
+        let bytes = decode_base64(encoded)?;
+        Self::from_bytes(&bytes)
+    }
+}
+
+/// Signs a 32-byte message digest, producing a compact signature.
+///
+/// Infallible: `msg_hash`'s length is fixed at the type level and
+/// `secret_key` was already validated by [`SecretKey::from_bytes`] or
+/// [`SecretKey::generate`].
+#[must_use]
+pub fn sign(msg_hash: &[u8; 32], secret_key: &SecretKey) -> Signature {
+    let secp = Secp256k1::new();
+    let message = Message::from_digest(*msg_hash);
+    let raw_signature = secp.sign_ecdsa(&message, &secret_key.0);
+    Signature(raw_signature.serialize_compact())
+}
+
+/// Verifies a signature against a message digest and public key.
+///
+/// # Errors
+///
+/// Returns [`NimbusError::AuthenticationFailed`] if `signature` is malformed
+/// or does not verify against `msg_hash` and `public_key`.
+pub fn verify(
+    msg_hash: &[u8; 32],
+    signature: &Signature,
+    public_key: &PublicKey,
+) -> NimbusResult<()> {
+    let secp = Secp256k1::new();
+    let message = Message::from_digest(*msg_hash);
+    let raw_signature = RawSignature::from_compact(&signature.0)
+        .map_err(|_| NimbusError::AuthenticationFailed)?;
+    secp.verify_ecdsa(&message, &raw_signature, &public_key.0)
+        .map_err(|_| NimbusError::AuthenticationFailed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sign_and_verify_round_trip() {
+        let secret_key = SecretKey::generate().unwrap();
+        let public_key = secret_key.public_key();
+        let msg_hash = [7u8; 32];
+
+        let signature = sign(&msg_hash, &secret_key);
+        assert!(verify(&msg_hash, &signature, &public_key).is_ok());
+    }
+
+    #[test]
+    fn verify_rejects_wrong_message() {
+        let secret_key = SecretKey::generate().unwrap();
+        let public_key = secret_key.public_key();
+
+        let signature = sign(&[1u8; 32], &secret_key);
+        let result = verify(&[2u8; 32], &signature, &public_key);
+        assert_eq!(result.unwrap_err(), NimbusError::AuthenticationFailed);
+    }
+
+    #[test]
+    fn verify_rejects_wrong_public_key() {
+        let secret_key = SecretKey::generate().unwrap();
+        let other_public_key = SecretKey::generate().unwrap().public_key();
+        let msg_hash = [3u8; 32];
+
+        let signature = sign(&msg_hash, &secret_key);
+        let result = verify(&msg_hash, &signature, &other_public_key);
+        assert_eq!(result.unwrap_err(), NimbusError::AuthenticationFailed);
+    }
+
+    #[test]
+    fn secret_key_from_bytes_rejects_zero_scalar() {
+        let result = SecretKey::from_bytes(&[0u8; 32]);
+        assert!(matches!(result, Err(NimbusError::InvalidInput)));
+    }
+
+    #[test]
+    fn public_key_from_bytes_rejects_invalid_point() {
+        let result = PublicKey::from_bytes(&[0u8; 33]);
+        assert_eq!(result.unwrap_err(), NimbusError::InvalidInput);
+    }
+
+    #[test]
+    fn signature_from_bytes_rejects_wrong_length() {
+        let result = Signature::from_bytes(&[0u8; 63]);
+        assert_eq!(result.unwrap_err(), NimbusError::InvalidLength);
+    }
+
+    #[test]
+    fn signature_base64_round_trip() {
+        let secret_key = SecretKey::generate().unwrap();
+        let signature = sign(&[9u8; 32], &secret_key);
+        let encoded = signature.to_base64().unwrap();
+        let decoded = Signature::from_base64(&encoded).unwrap();
+        assert_eq!(decoded, signature);
+    }
+}
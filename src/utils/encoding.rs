@@ -1,5 +1,23 @@
-use crate::error::{NimbusError, NimbusResult};
-use base64ct::{Base64, Encoding};
+//! Base64 encoding and decoding.
+//!
+//! Built on the `base64` crate rather than `base64ct`, so decode failures
+//! can be reported as a structured [`DecodeError`] instead of collapsing to
+//! a single generic error. `base64`'s encode/decode routines are
+//! variable-time, unlike `base64ct`'s constant-time implementation. Do not
+//! base64-encode or -decode secret key material through this module where
+//! timing side channels matter; encode only public values (ciphertext,
+//! signatures, public keys) here.
+
+use crate::error::{DecodeError, NimbusError, NimbusResult};
+use base64::Engine as Base64EngineExt;
+use base64::DecodeError as RawDecodeError;
+use base64::engine::GeneralPurpose;
+use base64::engine::general_purpose::{STANDARD, STANDARD_NO_PAD, URL_SAFE};
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 
 pub const BASE64_MAX_INPUT_SIZE: usize = usize::MAX / 4;
 
@@ -23,18 +41,72 @@ pub trait Encoder {
     fn max_input_size(&self) -> usize;
 }
 
-impl Encoder for Base64 {
+/// Which Base64 alphabet and padding policy a [`Base64Engine`] uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Base64Alphabet {
+    /// RFC 4648 standard alphabet, `=`-padded.
+    Standard,
+    /// RFC 4648 URL- and filename-safe alphabet, `=`-padded.
+    UrlSafe,
+    /// RFC 4648 standard alphabet, unpadded.
+    NoPad,
+}
+
+/// A configurable Base64 [`Encoder`] selecting an alphabet and padding
+/// policy, so ciphertext can be transported as plain standard Base64 or as
+/// URL-safe Base64 without re-escaping.
+pub struct Base64Engine(Base64Alphabet);
+
+impl Base64Engine {
+    #[must_use]
+    pub const fn new(alphabet: Base64Alphabet) -> Self {
+        Self(alphabet)
+    }
+
+    fn engine(&self) -> &'static GeneralPurpose {
+        match self.0 {
+            Base64Alphabet::Standard => &STANDARD,
+            Base64Alphabet::UrlSafe => &URL_SAFE,
+            Base64Alphabet::NoPad => &STANDARD_NO_PAD,
+        }
+    }
+}
+
+impl From<RawDecodeError> for DecodeError {
+    fn from(err: RawDecodeError) -> Self {
+        match err {
+            RawDecodeError::InvalidByte(offset, byte) => DecodeError::InvalidByte(offset, byte),
+            RawDecodeError::InvalidLength(_) => DecodeError::InvalidLength,
+            RawDecodeError::InvalidLastSymbol(offset, byte) => {
+                DecodeError::InvalidLastSymbol(offset, byte)
+            }
+            RawDecodeError::InvalidPadding => DecodeError::InvalidPadding,
+        }
+    }
+}
+
+/// Rejects input longer than `max`, factored out of [`Base64Engine::encode`]
+/// so the rejection path can be exercised directly without allocating an
+/// input anywhere near `BASE64_MAX_INPUT_SIZE` bytes.
+fn check_max_input_size(len: usize, max: usize) -> Result<(), NimbusError> {
+    if len > max {
+        return Err(NimbusError::InvalidLength);
+    }
+    Ok(())
+}
+
+impl Encoder for Base64Engine {
     type Error = NimbusError;
 
     fn encode(&self, data: &[u8]) -> Result<String, Self::Error> {
-        if data.len() > self.max_input_size() {
-            return Err(NimbusError::InvalidLength);
-        }
-        Ok(Base64::encode_string(data))
+        check_max_input_size(data.len(), self.max_input_size())?;
+        Ok(self.engine().encode(data))
     }
 
     fn decode(&self, data: &str) -> Result<Vec<u8>, Self::Error> {
-        Base64::decode_vec(data).map_err(|_| NimbusError::InvalidInput)
+        self.engine()
+            .decode(data)
+            .map_err(|err| NimbusError::Decode(DecodeError::from(err)))
     }
 
     fn max_input_size(&self) -> usize {
@@ -42,206 +114,121 @@ impl Encoder for Base64 {
     }
 }
 
-fn encode_with<E: Encoder>(encoder: &E, data: &[u8]) -> NimbusResult<String> {
-    encoder.encode(data).map_err(|_| NimbusError::InvalidInput)
+/// Encodes the given byte data into a standard Base64 string.
+///
+/// # Errors
+///
+/// Returns `NimbusError::InvalidLength` if the input data is too large, or
+/// `NimbusError::Decode` is never returned here; encoding cannot fail once
+/// the length check passes.
+pub fn encode_base64(data: &[u8]) -> NimbusResult<String> {
+    Base64Engine::new(Base64Alphabet::Standard).encode(data)
 }
 
-fn decode_with<E: Encoder>(encoder: &E, data: &str) -> NimbusResult<Vec<u8>> {
-    encoder.decode(data).map_err(|_| NimbusError::InvalidInput)
+/// Decodes the given standard Base64 string into bytes.
+///
+/// # Errors
+///
+/// Returns `NimbusError::Decode` carrying the specific [`DecodeError`]
+/// (invalid byte, length, last symbol, or padding) if `data` is malformed
+/// Base64.
+pub fn decode_base64(data: &str) -> NimbusResult<Vec<u8>> {
+    Base64Engine::new(Base64Alphabet::Standard).decode(data)
 }
 
-/// Encodes the given byte data into a Base64 string.
+/// Encodes the given byte data into a URL-safe Base64 string.
 ///
 /// # Errors
 ///
-/// Returns `NimbusError::InvalidLength` if the input data is too large,
-/// or `NimbusError::InvalidInput` if encoding fails.
-pub fn encode_base64(data: &[u8]) -> NimbusResult<String> {
-    encode_with(&Base64, data)
+/// Returns `NimbusError::InvalidLength` if the input data is too large.
+pub fn encode_base64_url(data: &[u8]) -> NimbusResult<String> {
+    Base64Engine::new(Base64Alphabet::UrlSafe).encode(data)
 }
 
-/// Decodes the given Base64 string into bytes.
+/// Decodes the given URL-safe Base64 string into bytes.
 ///
 /// # Errors
 ///
-/// Returns `NimbusError::InvalidInput` if the input string is invalid
-/// or malformed Base64.
-pub fn decode_base64(data: &str) -> NimbusResult<Vec<u8>> {
-    decode_with(&Base64, data)
+/// Returns `NimbusError::Decode` carrying the specific [`DecodeError`] if
+/// `data` is malformed URL-safe Base64.
+pub fn decode_base64_url(data: &str) -> NimbusResult<Vec<u8>> {
+    Base64Engine::new(Base64Alphabet::UrlSafe).decode(data)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    struct MockEncoder {
-        input_too_large: bool,
-        decode_failure: bool,
-        encode_failure: bool,
-    }
-
-    impl MockEncoder {
-        fn new_success() -> Self {
-            Self {
-                input_too_large: false,
-                decode_failure: false,
-                encode_failure: false,
-            }
-        }
-
-        fn new_input_too_large() -> Self {
-            Self {
-                input_too_large: true,
-                decode_failure: false,
-                encode_failure: false,
-            }
-        }
-
-        fn new_decode_failure() -> Self {
-            Self {
-                input_too_large: false,
-                decode_failure: true,
-                encode_failure: false,
-            }
-        }
-
-        fn new_encode_failure() -> Self {
-            Self {
-                input_too_large: false,
-                decode_failure: false,
-                encode_failure: true,
-            }
-        }
-    }
-
-    impl Encoder for MockEncoder {
-        type Error = NimbusError;
-
-        fn encode(&self, _data: &[u8]) -> Result<String, Self::Error> {
-            if self.input_too_large {
-                return Err(NimbusError::InvalidLength);
-            }
-            if self.encode_failure {
-                return Err(NimbusError::InvalidInput);
-            }
-            Ok(String::from("test"))
-        }
-
-        fn decode(&self, _data: &str) -> Result<Vec<u8>, Self::Error> {
-            if self.decode_failure {
-                return Err(NimbusError::InvalidInput);
-            }
-            Ok(b"test".to_vec())
-        }
-
-        fn max_input_size(&self) -> usize {
-            usize::MAX
-        }
-    }
-
     #[test]
-    fn base64_encoder_encode_success() {
-        let encoder = Base64;
+    fn base64_engine_encode_success() {
+        let encoder = Base64Engine::new(Base64Alphabet::Standard);
         let data = b"Hello, World!";
         let result = encoder.encode(data);
         assert!(result.is_ok());
-        let encoded_result = result.unwrap();
-        assert_eq!(encoded_result, "SGVsbG8sIFdvcmxkIQ==");
+        assert_eq!(result.unwrap(), "SGVsbG8sIFdvcmxkIQ==");
     }
 
     #[test]
-    fn base64_encoder_empty_data_roundtrip() {
-        let encoder = Base64;
+    fn base64_engine_empty_data_roundtrip() {
+        let encoder = Base64Engine::new(Base64Alphabet::Standard);
 
-        // Test encoding empty data
         let empty_bytes = b"";
         let encoded_result = encoder.encode(empty_bytes).unwrap();
         assert_eq!(encoded_result, "");
 
-        // Test decoding empty string
-        let empty_string = "";
-        let decoded = encoder.decode(empty_string).unwrap();
+        let decoded = encoder.decode("").unwrap();
         assert_eq!(decoded, b"");
 
-        // Test full roundtrip
         let roundtrip = encoder.decode(&encoded_result).unwrap();
         assert_eq!(roundtrip, empty_bytes);
     }
 
     #[test]
-    fn base64_encoder_encode_input_too_large() {
-        // We can't actually allocate usize::MAX/4 + 1 bytes in memory,
-        // but we can test the logic by using a mock encoder that simulates this condition
-        let mock = MockEncoder::new_input_too_large();
-        let data = b"test data";
-        let result = mock.encode(data);
-        assert_eq!(result.unwrap_err(), NimbusError::InvalidLength);
-    }
-
-    #[test]
-    fn base64_encoder_decode_success() {
-        let encoder = Base64;
-        let data = "SGVsbG8sIFdvcmxkIQ==";
-        let result = encoder.decode(data);
-        assert!(result.is_ok());
-        let decoded = result.unwrap();
+    fn base64_engine_decode_success() {
+        let encoder = Base64Engine::new(Base64Alphabet::Standard);
+        let decoded = encoder.decode("SGVsbG8sIFdvcmxkIQ==").unwrap();
         assert_eq!(decoded, b"Hello, World!");
     }
 
     #[test]
-    fn base64_encoder_decode_invalid_input() {
-        let encoder = Base64;
-        let data = "Invalid Base64!@#$%";
-        let result = encoder.decode(data);
-        assert_eq!(result.unwrap_err(), NimbusError::InvalidInput);
-    }
-
-    #[test]
-    fn base64_encoder_max_input_size() {
-        let encoder = Base64;
-        assert_eq!(encoder.max_input_size(), BASE64_MAX_INPUT_SIZE);
-    }
-
-    #[test]
-    fn encode_with_success() {
-        let mock = MockEncoder::new_success();
-        let data = b"test data";
-        let result = encode_with(&mock, data);
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), "test");
+    fn base64_engine_decode_reports_invalid_byte() {
+        let encoder = Base64Engine::new(Base64Alphabet::Standard);
+        let result = encoder.decode("SGVsbG8h!!!!");
+        match result.unwrap_err() {
+            NimbusError::Decode(DecodeError::InvalidByte(_, _)) => {}
+            other => panic!("expected Decode(InvalidByte), got {other:?}"),
+        }
     }
 
     #[test]
-    fn encode_with_input_too_large() {
-        let mock = MockEncoder::new_input_too_large();
-        let data = b"test data";
-        let result = encode_with(&mock, data);
-        assert_eq!(result.unwrap_err(), NimbusError::InvalidInput);
+    fn base64_engine_decode_reports_invalid_padding() {
+        let encoder = Base64Engine::new(Base64Alphabet::Standard);
+        // One padding character short of what the standard engine requires.
+        let result = encoder.decode("SGVsbG8sIFdvcmxkIQ=");
+        assert!(matches!(
+            result.unwrap_err(),
+            NimbusError::Decode(DecodeError::InvalidPadding)
+                | NimbusError::Decode(DecodeError::InvalidLength)
+        ));
     }
 
     #[test]
-    fn encode_with_encode_failure() {
-        let mock = MockEncoder::new_encode_failure();
-        let data = b"test data";
-        let result = encode_with(&mock, data);
-        assert_eq!(result.unwrap_err(), NimbusError::InvalidInput);
+    fn base64_engine_max_input_size() {
+        let encoder = Base64Engine::new(Base64Alphabet::Standard);
+        assert_eq!(encoder.max_input_size(), BASE64_MAX_INPUT_SIZE);
     }
 
     #[test]
-    fn decode_with_success() {
-        let mock = MockEncoder::new_success();
-        let data = "test data";
-        let result = decode_with(&mock, data);
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), b"test");
+    fn check_max_input_size_accepts_input_at_the_limit() {
+        assert!(check_max_input_size(10, 10).is_ok());
     }
 
     #[test]
-    fn decode_with_failure() {
-        let mock = MockEncoder::new_decode_failure();
-        let data = "test data";
-        let result = decode_with(&mock, data);
-        assert_eq!(result.unwrap_err(), NimbusError::InvalidInput);
+    fn check_max_input_size_rejects_oversized_input() {
+        assert_eq!(
+            check_max_input_size(11, 10).unwrap_err(),
+            NimbusError::InvalidLength
+        );
     }
 
     #[test]
@@ -249,69 +236,33 @@ mod tests {
         let data = b"Hello, Base64!";
         let result = encode_base64(data);
         assert!(result.is_ok());
-        let encoded = result.unwrap();
-        assert_eq!(encoded, "SGVsbG8sIEJhc2U2NCE=");
+        assert_eq!(result.unwrap(), "SGVsbG8sIEJhc2U2NCE=");
     }
 
     #[test]
     fn base64_api_empty_data_roundtrip() {
-        // Test encoding empty data
         let empty_bytes = b"";
         let encoded = encode_base64(empty_bytes).unwrap();
         assert_eq!(encoded, "");
 
-        // Test decoding empty string
-        let empty_string = "";
-        let decoded = decode_base64(empty_string).unwrap();
+        let decoded = decode_base64("").unwrap();
         assert_eq!(decoded, b"");
 
-        // Test full roundtrip
         let roundtrip = decode_base64(&encoded).unwrap();
         assert_eq!(roundtrip, empty_bytes);
     }
 
     #[test]
     fn decode_base64_success() {
-        let data = "SGVsbG8sIEJhc2U2NCE=";
-        let result = decode_base64(data);
+        let result = decode_base64("SGVsbG8sIEJhc2U2NCE=");
         assert!(result.is_ok());
-        let decoded = result.unwrap();
-        assert_eq!(decoded, b"Hello, Base64!");
+        assert_eq!(result.unwrap(), b"Hello, Base64!");
     }
 
     #[test]
     fn decode_base64_invalid_input() {
-        let data = "Invalid Base64 Data!@#";
-        let result = decode_base64(data);
-        assert_eq!(result.unwrap_err(), NimbusError::InvalidInput);
-    }
-
-    #[test]
-    fn mock_encoder_behavior_configurations() {
-        // Test success configuration
-        let success_mock = MockEncoder::new_success();
-        assert!(!success_mock.input_too_large);
-        assert!(!success_mock.decode_failure);
-        assert!(!success_mock.encode_failure);
-        assert_eq!(success_mock.max_input_size(), usize::MAX);
-
-        // Test input too large configuration
-        let large_input_mock = MockEncoder::new_input_too_large();
-        assert!(large_input_mock.input_too_large);
-        assert!(!large_input_mock.decode_failure);
-        assert!(!large_input_mock.encode_failure);
-
-        // Test decode failure configuration
-        let decode_fail_mock = MockEncoder::new_decode_failure();
-        assert!(!decode_fail_mock.input_too_large);
-        assert!(decode_fail_mock.decode_failure);
-        assert!(!decode_fail_mock.encode_failure);
-
-        // Test encode failure configuration
-        let encode_fail_mock = MockEncoder::new_encode_failure();
-        assert!(!encode_fail_mock.input_too_large);
-        assert!(!encode_fail_mock.decode_failure);
-        assert!(encode_fail_mock.encode_failure);
+        let result = decode_base64("Invalid Base64 Data!@#");
+        assert!(matches!(result.unwrap_err(), NimbusError::Decode(_)));
     }
 
     #[test]
@@ -329,4 +280,31 @@ mod tests {
         let decoded = decode_base64(&encoded).unwrap();
         assert_eq!(decoded, original_data);
     }
+
+    #[test]
+    fn url_safe_roundtrip() {
+        // Bytes chosen so the standard alphabet would emit '+' and '/'.
+        let original_data = [0xFB, 0xFF, 0xBF];
+        let encoded = encode_base64_url(&original_data).unwrap();
+        assert!(!encoded.contains('+') && !encoded.contains('/'));
+        let decoded = decode_base64_url(&encoded).unwrap();
+        assert_eq!(decoded, original_data);
+    }
+
+    #[test]
+    fn url_safe_rejects_standard_alphabet_input() {
+        // Standard-alphabet output containing '+' or '/' is not valid
+        // URL-safe Base64.
+        let result = decode_base64_url("++//");
+        assert!(matches!(result.unwrap_err(), NimbusError::Decode(_)));
+    }
+
+    #[test]
+    fn no_pad_engine_emits_no_padding() {
+        let encoder = Base64Engine::new(Base64Alphabet::NoPad);
+        let encoded = encoder.encode(b"Hello, World!").unwrap();
+        assert!(!encoded.contains('='));
+        let decoded = encoder.decode(&encoded).unwrap();
+        assert_eq!(decoded, b"Hello, World!");
+    }
 }
@@ -1,7 +1,15 @@
+#[cfg(any(feature = "std", test))]
 use crate::error::{NimbusError, NimbusResult};
+#[cfg(feature = "std")]
 use rand::TryRngCore;
+#[cfg(feature = "std")]
 use rand::rngs::OsRng;
 
+#[cfg(all(not(feature = "std"), test))]
+use alloc::vec;
+#[cfg(all(not(feature = "std"), test))]
+use alloc::vec::Vec;
+
 /// Standard nonce sizes in bytes for common cryptographic operations
 pub const NONCE_96_BIT_SIZE: usize = 12; // 96 bits = 12 bytes
 pub const NONCE_192_BIT_SIZE: usize = 24; // 192 bits = 24 bytes
@@ -28,6 +36,7 @@ pub trait SecureRandomSource {
     fn try_next_u64(&mut self) -> Result<u64, Self::Error>;
 }
 
+#[cfg(feature = "std")]
 impl SecureRandomSource for OsRng {
     type Error = <OsRng as TryRngCore>::Error;
 
@@ -40,6 +49,7 @@ impl SecureRandomSource for OsRng {
     }
 }
 
+#[cfg(any(feature = "std", test))]
 fn secure_random_bytes<R: SecureRandomSource>(
     rng: &mut R,
     byte_count: usize,
@@ -50,11 +60,13 @@ fn secure_random_bytes<R: SecureRandomSource>(
     Ok(buffer)
 }
 
+#[cfg(any(feature = "std", test))]
 fn secure_random_u64<R: SecureRandomSource>(rng: &mut R) -> NimbusResult<u64> {
     rng.try_next_u64()
         .map_err(|_| NimbusError::RandomGenerationFailed)
 }
 
+#[cfg(feature = "std")]
 fn generate_nonce(byte_count: usize) -> NimbusResult<Vec<u8>> {
     let mut rng = OsRng;
     secure_random_bytes(&mut rng, byte_count)
@@ -66,6 +78,7 @@ fn generate_nonce(byte_count: usize) -> NimbusResult<Vec<u8>> {
 ///
 /// Returns [`NimbusError::RandomGenerationFailed`] if the system's secure random
 /// number generator fails to produce cryptographically secure random data.
+#[cfg(feature = "std")]
 pub fn generate_aes_gcm_nonce() -> NimbusResult<Vec<u8>> {
     generate_nonce(NONCE_96_BIT_SIZE)
 }
@@ -76,6 +89,7 @@ pub fn generate_aes_gcm_nonce() -> NimbusResult<Vec<u8>> {
 ///
 /// Returns [`NimbusError::RandomGenerationFailed`] if the system's secure random
 /// number generator fails to produce cryptographically secure random data.
+#[cfg(feature = "std")]
 pub fn generate_extended_nonce() -> NimbusResult<Vec<u8>> {
     generate_nonce(NONCE_192_BIT_SIZE)
 }
@@ -86,11 +100,47 @@ pub fn generate_extended_nonce() -> NimbusResult<Vec<u8>> {
 ///
 /// Returns [`NimbusError::RandomGenerationFailed`] if the system's secure random
 /// number generator fails to produce cryptographically secure random data.
+#[cfg(feature = "std")]
 pub fn generate_random_u64() -> NimbusResult<u64> {
     let mut rng = OsRng;
     secure_random_u64(&mut rng)
 }
 
+/// A deterministic, seed-derived [`SecureRandomSource`] for reproducible
+/// test vectors and known-answer tests.
+///
+/// # Warning
+///
+/// This is **test-only** and must never be used to generate real keys or
+/// nonces: given the same 32-byte seed it always produces the same byte
+/// stream, the way libsodium's `randombytes_buf_deterministic` does. It
+/// never touches the OS entropy pool.
+pub struct SeededRandomSource(rand_chacha::ChaCha20Rng);
+
+impl SeededRandomSource {
+    /// Creates a deterministic random source from a 32-byte seed.
+    #[must_use]
+    pub fn from_seed(seed: [u8; 32]) -> Self {
+        use rand::SeedableRng;
+        Self(rand_chacha::ChaCha20Rng::from_seed(seed))
+    }
+}
+
+impl SecureRandomSource for SeededRandomSource {
+    type Error = core::convert::Infallible;
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Self::Error> {
+        use rand::RngCore;
+        self.0.fill_bytes(dest);
+        Ok(())
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        use rand::RngCore;
+        Ok(self.0.next_u64())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -206,4 +256,36 @@ mod tests {
         let nonce2 = generate_aes_gcm_nonce().unwrap();
         assert_ne!(nonce1, nonce2);
     }
+
+    #[test]
+    fn seeded_source_is_deterministic_for_same_seed() {
+        let mut first = SeededRandomSource::from_seed([1u8; 32]);
+        let mut second = SeededRandomSource::from_seed([1u8; 32]);
+
+        let bytes_first = secure_random_bytes(&mut first, 32).unwrap();
+        let bytes_second = secure_random_bytes(&mut second, 32).unwrap();
+        assert_eq!(bytes_first, bytes_second);
+
+        assert_eq!(
+            secure_random_u64(&mut first).unwrap(),
+            secure_random_u64(&mut second).unwrap()
+        );
+    }
+
+    #[test]
+    fn seeded_source_differs_for_different_seeds() {
+        let mut first = SeededRandomSource::from_seed([1u8; 32]);
+        let mut second = SeededRandomSource::from_seed([2u8; 32]);
+
+        let bytes_first = secure_random_bytes(&mut first, 32).unwrap();
+        let bytes_second = secure_random_bytes(&mut second, 32).unwrap();
+        assert_ne!(bytes_first, bytes_second);
+    }
+
+    #[test]
+    fn seeded_source_never_fails() {
+        let mut source = SeededRandomSource::from_seed([3u8; 32]);
+        assert!(secure_random_bytes(&mut source, 16).is_ok());
+        assert!(secure_random_u64(&mut source).is_ok());
+    }
 }